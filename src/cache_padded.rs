@@ -0,0 +1,43 @@
+//! Cache-line padding for hot [`SeqRing`](crate::SeqRing) fields.
+//!
+//! The producer's constant stores to `next_seq`/`published_seq` and the consumer's
+//! reads of the same words are on opposite sides of the SPSC hand-off. If they share a
+//! cache line with each other (or with the rarely-touched `producer_taken` flag and
+//! `consumer_count` counter), every store bounces the line between cores even though the
+//! fields are logically independent. Padding each field out to its own line trades RAM
+//! for that coherence traffic, which is the right trade for the "fast producer" this
+//! crate targets.
+//!
+//! Padding is gated behind the `cache-padded` feature (on by default) so single-core
+//! embedded targets that gain nothing from it can opt out and get the field back at its
+//! natural size.
+
+/// Wraps `T` so it occupies a full cache line on its own, preventing false sharing with
+/// neighboring fields.
+///
+/// With the `cache-padded` feature disabled this is a zero-cost, unpadded wrapper.
+#[cfg_attr(feature = "cache-padded", repr(align(64)))]
+pub(crate) struct CachePadded<T>(T);
+
+impl<T> CachePadded<T> {
+    #[inline]
+    pub(crate) const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> core::ops::Deref for CachePadded<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> core::ops::DerefMut for CachePadded<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
@@ -1,7 +1,7 @@
 //! Eventing primitives for no-std embedded targets.
 //!
 //! # Highlights
-//! - Lock-free SPSC sequence ring for high-throughput telemetry.
+//! - Lock-free single-producer, multi-consumer sequence ring for high-throughput telemetry.
 //! - No allocation, no dynamic dispatch.
 //! - Designed for fast producers and potentially slower consumers.
 //!
@@ -27,14 +27,35 @@
 //! For targets that lack 32-bit atomics (for example `thumbv6m-none-eabi`), enable
 //! `portable-atomic-unsafe-assume-single-core` or `portable-atomic-critical-section`.
 //!
+//! # 64-bit sequence numbers
+//! Sequence numbers are [`Seq`] (`u32` by default). At high telemetry rates `u32` wraps
+//! in minutes-to-hours; enabling the `64bit-seq` feature switches `Seq` to `u64` so a
+//! stream running for days has an unambiguous, monotonically increasing cursor. This
+//! requires 64-bit atomics (native or via `portable-atomic`).
+//!
+//! # Cache-line padding
+//! The `cache-padded` feature (on by default) pads the producer's and consumer's hot
+//! fields onto separate cache lines to avoid false sharing on multi-core targets. Tiny
+//! single-core MCUs that gain nothing from this can disable it to get the RAM back.
+//!
+//! # Model-checked concurrency
+//! Under `#[cfg(loom)]`, the atomics backing `SeqRing` are swapped for `loom`'s so the
+//! producer/consumer protocol can be checked against many interleavings rather than one.
+//! Run with `RUSTFLAGS="--cfg loom" cargo test --release --lib -- --test-threads=1`.
+//!
 //! # Safety and concurrency
-//! This crate is SPSC by design: exactly one producer and one consumer must be active.
-//! `producer()`/`consumer()` will panic if called while another handle of the same kind is active.
-//! Using unsafe to bypass these constraints (or sharing handles concurrently) is undefined behavior.
+//! This crate is single-producer, multi-consumer by design: exactly one producer and up
+//! to [`seq_ring::MAX_CONSUMERS`] consumers may be active at once, each broadcasting the
+//! full stream to its own cursor. `producer()` panics if a producer handle is already
+//! active; `consumer()` panics once `MAX_CONSUMERS` consumer handles are active.
+//! Using unsafe to bypass these constraints (or sharing a single handle concurrently) is
+//! undefined behavior.
 //!
 //! # Semantics
-//! - Sequence numbers are monotonically increasing `u32` values; `0` is reserved for "empty".
+//! - Sequence numbers ([`Seq`]) are monotonically increasing; `0` is reserved for "empty".
 //! - `poll_one`/`poll_up_to` drain in-order and return `PollStats`.
+//! - `copy_up_to` drains in-order like `poll_up_to`, writing straight into a slice
+//!   instead of invoking a per-item closure, for batch processing (SIMD, DMA, ...).
 //! - `latest` reads the newest value without advancing the consumer cursor.
 //! - If the consumer lags by more than `N`, it skips ahead and reports drops via `PollStats`.
 #![no_std]
@@ -45,8 +66,22 @@ compile_error!(
 enable either the portable-atomic-unsafe-assume-single-core or portable-atomic-critical-section feature."
 );
 
+#[cfg(all(
+    feature = "64bit-seq",
+    not(target_has_atomic = "64"),
+    not(feature = "portable-atomic")
+))]
+compile_error!(
+    "the 64bit-seq feature requires 64-bit atomics. For targets without them, \
+enable either the portable-atomic-unsafe-assume-single-core or portable-atomic-critical-section feature."
+);
+
+mod cache_padded;
+mod seq;
 pub mod seq_ring;
+mod sync;
 
+pub use seq::Seq;
 pub use seq_ring::{Consumer, PollStats, Producer, SeqRing};
 
 #[cfg(test)]
@@ -0,0 +1,21 @@
+//! Sequence-number width selection.
+//!
+//! By default sequence numbers are `u32`, which at high telemetry rates cycles in
+//! minutes-to-hours (see the `0`-reserved wrap-to-1 handling in
+//! [`SeqRing::push_inner`](crate::seq_ring::SeqRing)). Enabling the `64bit-seq` feature
+//! switches every sequence number in the crate to `u64`, giving a stream running for
+//! days an unambiguous, monotonically increasing cursor that doubles as a cheap event
+//! counter. The reserved-`0`-means-empty handling and the lag/skip-ahead arithmetic
+//! carry over unchanged, just widened.
+
+/// The crate-wide sequence number type: `u32` by default, or `u64` with the
+/// `64bit-seq` feature enabled.
+#[cfg(not(feature = "64bit-seq"))]
+pub type Seq = u32;
+#[cfg(feature = "64bit-seq")]
+pub type Seq = u64;
+
+#[cfg(not(feature = "64bit-seq"))]
+pub(crate) use crate::sync::AtomicU32 as AtomicSeq;
+#[cfg(feature = "64bit-seq")]
+pub(crate) use crate::sync::AtomicU64 as AtomicSeq;
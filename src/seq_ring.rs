@@ -1,11 +1,16 @@
-//! Lock-free SPSC overwrite ring for high-rate telemetry in no-std contexts.
+//! Lock-free single-producer, multi-consumer overwrite ring for high-rate telemetry in
+//! no-std contexts.
 //!
 //! # Overview
-//! - Single producer, single consumer.
+//! - Single producer, up to [`MAX_CONSUMERS`] independent consumers (broadcast fan-out).
+//!   Each consumer sees the full stream from its own cursor; a slow consumer only drops
+//!   its own items, never the producer's or another consumer's.
 //! - Producer never blocks; new writes overwrite the oldest slots when the ring wraps.
-//! - Sequence numbers are monotonically increasing `u32`; `0` is reserved to mean "empty".
-//! - The consumer can drain in-order (`poll_one`/`poll_up_to`) or sample the newest value (`latest`).
-//! - If the consumer lags by more than `N`, it skips ahead and reports the number of dropped items.
+//! - Sequence numbers ([`Seq`](crate::Seq)) are monotonically increasing; `0` is reserved
+//!   to mean "empty". `Seq` is `u32` by default, or `u64` with the `64bit-seq` feature.
+//! - Each consumer can drain in-order (`poll_one`/`poll_up_to`/`copy_up_to`) or sample the
+//!   newest value (`latest`).
+//! - If a consumer lags by more than `N`, it skips ahead and reports the number of dropped items.
 //!
 //! # Memory ordering
 //! The producer writes the value, publishes the per-slot sequence, then publishes the newest
@@ -16,29 +21,29 @@
 //! - `T` is `Copy` to allow returning values by copy without allocation.
 //! - The `&T` passed to hooks is a reference to a local copy made during the read.
 
-use core::cell::{Cell, UnsafeCell};
+use core::cell::Cell;
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 use core::sync::atomic::Ordering;
-#[cfg(target_has_atomic = "32")]
-use core::sync::atomic::{AtomicBool, AtomicU32};
-#[cfg(all(not(target_has_atomic = "32"), feature = "portable-atomic"))]
-use portable_atomic::{AtomicBool, AtomicU32};
-#[cfg(test)]
-use core::sync::atomic::AtomicUsize;
-
-fn atomic_u32_array<const N: usize>(init: u32) -> [AtomicU32; N] {
-    core::array::from_fn(|_| AtomicU32::new(init))
+
+use crate::cache_padded::CachePadded;
+use crate::seq::{AtomicSeq, Seq};
+use crate::sync::{AtomicBool, AtomicUsize, UnsafeCell};
+
+fn atomic_seq_array<const N: usize>(init: Seq) -> [AtomicSeq; N] {
+    core::array::from_fn(|_| AtomicSeq::new(init))
 }
 
 fn unsafe_cell_array<T, const N: usize>() -> [UnsafeCell<MaybeUninit<T>>; N] {
     core::array::from_fn(|_| UnsafeCell::new(MaybeUninit::uninit()))
 }
 
-#[cfg(test)]
+// Loom atomics aren't `const`-constructible and loom disallows real statics, so this
+// hook (only ever used to poke at a single-threaded test) is unavailable under loom.
+#[cfg(all(test, not(loom)))]
 static TEST_AFTER_READ_TARGET: AtomicUsize = AtomicUsize::new(0);
-#[cfg(test)]
-static TEST_AFTER_READ_SEQ: AtomicU32 = AtomicU32::new(0);
+#[cfg(all(test, not(loom)))]
+static TEST_AFTER_READ_SEQ: AtomicSeq = AtomicSeq::new(0);
 
 #[must_use]
 #[derive(Copy, Clone, Debug)]
@@ -48,18 +53,23 @@ pub struct PollStats {
     /// Number of items skipped because the consumer lagged or slots were overwritten.
     pub dropped: usize,
     /// Newest sequence observed while polling.
-    pub newest: u32,
+    pub newest: Seq,
 }
 
-/// Overwrite ring for SPSC high-rate telemetry.
-/// Producer never waits; consumer may drop if it lags > N.
+/// Maximum number of independent [`Consumer`] handles a [`SeqRing`] can hand out at
+/// once. Each sees the full broadcast stream from its own cursor; a slow consumer only
+/// affects its own drop count, never the producer or any other consumer.
+pub const MAX_CONSUMERS: usize = 4;
+
+/// Overwrite ring for single-producer, multi-consumer high-rate telemetry.
+/// Producer never waits; a consumer may drop if it lags > N.
 pub struct SeqRing<T: Copy, const N: usize> {
-    next_seq: AtomicU32,
-    published_seq: AtomicU32,
-    slot_seq: [AtomicU32; N],
+    next_seq: CachePadded<AtomicSeq>,
+    published_seq: CachePadded<AtomicSeq>,
+    slot_seq: [AtomicSeq; N],
     slots: [UnsafeCell<MaybeUninit<T>>; N],
-    producer_taken: AtomicBool,
-    consumer_taken: AtomicBool,
+    producer_taken: CachePadded<AtomicBool>,
+    consumer_count: CachePadded<AtomicUsize>,
 }
 
 // SAFETY: SeqRing is Sync because the producer/consumer handles enforce SPSC usage,
@@ -76,18 +86,24 @@ impl<T: Copy, const N: usize> SeqRing<T, N> {
     pub fn new() -> Self {
         assert!(N > 0);
         Self {
-            next_seq: AtomicU32::new(0),
-            published_seq: AtomicU32::new(0),
-            slot_seq: atomic_u32_array::<N>(0),
+            next_seq: CachePadded::new(AtomicSeq::new(0)),
+            published_seq: CachePadded::new(AtomicSeq::new(0)),
+            slot_seq: atomic_seq_array::<N>(0),
             slots: unsafe_cell_array::<T, N>(),
-            producer_taken: AtomicBool::new(false),
-            consumer_taken: AtomicBool::new(false),
+            producer_taken: CachePadded::new(AtomicBool::new(false)),
+            consumer_count: CachePadded::new(AtomicUsize::new(0)),
         }
     }
 
+    /// Maps a sequence number to its slot index. Branchless `& (N - 1)` when `N` is a
+    /// power of two (the common case), falling back to `%` otherwise.
     #[inline(always)]
-    const fn idx_for(seq: u32) -> usize {
-        ((seq.wrapping_sub(1)) as usize) % N
+    const fn idx_for(seq: Seq) -> usize {
+        if N.is_power_of_two() {
+            (seq.wrapping_sub(1) as usize) & (N - 1)
+        } else {
+            (seq.wrapping_sub(1) as usize) % N
+        }
     }
 
     /// Create the producer handle. Only one producer may be active.
@@ -106,16 +122,31 @@ impl<T: Copy, const N: usize> SeqRing<T, N> {
         }
     }
 
-    /// Create the consumer handle. Only one consumer may be active.
+    /// Create a consumer handle. Up to [`MAX_CONSUMERS`] independent consumers may be
+    /// active at once; each receives the complete broadcast sequence from its own
+    /// cursor and tracks its own drops, so a slow consumer never affects the producer
+    /// or any other consumer.
     ///
     /// # Panics
-    /// Panics if a consumer handle is already active.
+    /// Panics if `MAX_CONSUMERS` consumer handles are already active.
     #[inline]
     pub fn consumer(&self) -> Consumer<'_, T, N> {
-        assert!(
-            !self.consumer_taken.swap(true, Ordering::AcqRel),
-            "SeqRing::consumer() called while a consumer is active"
-        );
+        let mut current = self.consumer_count.load(Ordering::Acquire);
+        loop {
+            assert!(
+                current < MAX_CONSUMERS,
+                "SeqRing::consumer() called with MAX_CONSUMERS consumer handles already active"
+            );
+            match self.consumer_count.compare_exchange_weak(
+                current,
+                current + 1,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(observed) => current = observed,
+            }
+        }
         Consumer {
             ring: self,
             last_seq: 0,
@@ -125,12 +156,12 @@ impl<T: Copy, const N: usize> SeqRing<T, N> {
     }
 
     #[inline]
-    fn newest_seq(&self) -> u32 {
+    fn newest_seq(&self) -> Seq {
         self.published_seq.load(Ordering::Acquire)
     }
 
     #[inline]
-    fn push_inner(&self, value: T) -> u32 {
+    fn push_inner(&self, value: T) -> Seq {
         let mut seq = self
             .next_seq
             .fetch_add(1, Ordering::Relaxed)
@@ -141,7 +172,7 @@ impl<T: Copy, const N: usize> SeqRing<T, N> {
         }
 
         let idx = Self::idx_for(seq);
-        unsafe { (*self.slots[idx].get()).as_mut_ptr().write(value) };
+        self.write_slot(idx, value);
 
         self.slot_seq[idx].store(seq, Ordering::Release);
         self.published_seq.store(seq, Ordering::Release);
@@ -149,7 +180,7 @@ impl<T: Copy, const N: usize> SeqRing<T, N> {
     }
 
     #[inline]
-    fn read_seq_inner(&self, seq: u32) -> Option<T> {
+    fn read_seq_inner(&self, seq: Seq) -> Option<T> {
         let idx = Self::idx_for(seq);
 
         let s1 = self.slot_seq[idx].load(Ordering::Acquire);
@@ -157,9 +188,9 @@ impl<T: Copy, const N: usize> SeqRing<T, N> {
             return None;
         }
 
-        let v = unsafe { (*self.slots[idx].get()).assume_init_read() };
+        let v = self.read_slot(idx);
 
-        #[cfg(test)]
+        #[cfg(all(test, not(loom)))]
         self.test_after_read_hook(idx);
 
         let s2 = self.slot_seq[idx].load(Ordering::Acquire);
@@ -170,7 +201,37 @@ impl<T: Copy, const N: usize> SeqRing<T, N> {
         Some(v)
     }
 
-    #[cfg(test)]
+    /// Writes `value` into slot `idx`.
+    ///
+    /// Under `#[cfg(loom)]` the slot's `UnsafeCell` only exposes closure-based
+    /// `with`/`with_mut` accessors (see [`crate::sync`]), so the write goes through a
+    /// closure there instead of a raw pointer.
+    #[inline]
+    fn write_slot(&self, idx: usize, value: T) {
+        #[cfg(not(loom))]
+        unsafe {
+            (*self.slots[idx].get()).as_mut_ptr().write(value)
+        };
+        #[cfg(loom)]
+        self.slots[idx].with_mut(|ptr| unsafe { (*ptr).as_mut_ptr().write(value) });
+    }
+
+    /// Reads slot `idx`, assuming it has been initialized by a prior [`Self::write_slot`].
+    ///
+    /// See [`Self::write_slot`] for why this isn't a plain pointer dereference under loom.
+    #[inline]
+    fn read_slot(&self, idx: usize) -> T {
+        #[cfg(not(loom))]
+        {
+            unsafe { (*self.slots[idx].get()).assume_init_read() }
+        }
+        #[cfg(loom)]
+        {
+            self.slots[idx].with(|ptr| unsafe { (*ptr).assume_init_read() })
+        }
+    }
+
+    #[cfg(all(test, not(loom)))]
     fn test_after_read_hook(&self, idx: usize) {
         let target = TEST_AFTER_READ_TARGET.load(Ordering::Acquire);
         if target == self as *const _ as usize {
@@ -194,7 +255,7 @@ impl<'a, T: Copy, const N: usize> Producer<'a, T, N> {
     ///
     /// Returns the sequence number assigned to the write (never 0).
     #[inline]
-    pub fn push(&self, value: T) -> u32 {
+    pub fn push(&self, value: T) -> Seq {
         self.ring.push_inner(value)
     }
 }
@@ -210,7 +271,7 @@ impl<'a, T: Copy, const N: usize> Drop for Producer<'a, T, N> {
 /// This handle is `!Sync` to prevent concurrent consumers.
 pub struct Consumer<'a, T: Copy, const N: usize> {
     ring: &'a SeqRing<T, N>,
-    last_seq: u32,
+    last_seq: Seq,
     dropped_accum: usize,
     _not_sync: PhantomData<Cell<()>>,
 }
@@ -231,7 +292,7 @@ impl<'a, T: Copy, const N: usize> Consumer<'a, T, N> {
     /// Drain at most one item (in-order).
     /// Returns true if an item was delivered to the hook.
     #[inline]
-    pub fn poll_one(&mut self, hook: impl FnOnce(u32, &T)) -> bool {
+    pub fn poll_one(&mut self, hook: impl FnOnce(Seq, &T)) -> bool {
         let mut hook = Some(hook);
         let stats = self.poll_up_to(1, |seq, v| {
             if let Some(hook) = hook.take() {
@@ -246,7 +307,7 @@ impl<'a, T: Copy, const N: usize> Consumer<'a, T, N> {
     ///
     /// If `max == 0`, this returns immediately with `read = 0`, `dropped = 0`, and
     /// `newest` set to the latest published sequence.
-    pub fn poll_up_to(&mut self, max: usize, mut hook: impl FnMut(u32, &T)) -> PollStats {
+    pub fn poll_up_to(&mut self, max: usize, mut hook: impl FnMut(Seq, &T)) -> PollStats {
         if max == 0 {
             return PollStats {
                 read: 0,
@@ -276,7 +337,7 @@ impl<'a, T: Copy, const N: usize> Consumer<'a, T, N> {
             let lag = newest.wrapping_sub(self.last_seq) as usize;
             if lag > N {
                 let next = self.last_seq.wrapping_add(1);
-                let keep_from = newest.wrapping_sub((N - 1) as u32);
+                let keep_from = newest.wrapping_sub((N - 1) as Seq);
                 let jump_drops = keep_from.wrapping_sub(next) as usize;
                 dropped += jump_drops;
                 self.last_seq = keep_from.wrapping_sub(1);
@@ -307,12 +368,83 @@ impl<'a, T: Copy, const N: usize> Consumer<'a, T, N> {
         }
     }
 
+    /// Drain in-order like [`poll_up_to`](Self::poll_up_to), but copy validated values
+    /// directly into `out` instead of invoking a per-item closure.
+    ///
+    /// Stops after `min(out.len(), lag)` items. Only `out[..stats.read]` is written;
+    /// the rest of `out` is left untouched. This lets a consumer process a whole batch
+    /// in one pass (SIMD, a single DMA, a block write to flash) instead of paying
+    /// closure-call overhead per element.
+    ///
+    /// If `out` is empty, this returns immediately with `read = 0`, `dropped = 0`, and
+    /// `newest` set to the latest published sequence.
+    pub fn copy_up_to(&mut self, out: &mut [T]) -> PollStats {
+        if out.is_empty() {
+            return PollStats {
+                read: 0,
+                dropped: 0,
+                newest: self.ring.newest_seq(),
+            };
+        }
+
+        let mut newest = self.ring.newest_seq();
+        if newest == 0 || newest == self.last_seq {
+            return PollStats {
+                read: 0,
+                dropped: 0,
+                newest,
+            };
+        }
+
+        let mut read = 0usize;
+        let mut dropped = 0usize;
+
+        while read < out.len() {
+            newest = self.ring.newest_seq();
+            if self.last_seq == newest {
+                break;
+            }
+
+            let lag = newest.wrapping_sub(self.last_seq) as usize;
+            if lag > N {
+                let next = self.last_seq.wrapping_add(1);
+                let keep_from = newest.wrapping_sub((N - 1) as Seq);
+                let jump_drops = keep_from.wrapping_sub(next) as usize;
+                dropped += jump_drops;
+                self.last_seq = keep_from.wrapping_sub(1);
+                continue;
+            }
+
+            let next = self.last_seq.wrapping_add(1);
+
+            match self.ring.read_seq_inner(next) {
+                Some(v) => {
+                    out[read] = v;
+                    self.last_seq = next;
+                    read += 1;
+                }
+                None => {
+                    self.last_seq = next;
+                    dropped += 1;
+                }
+            }
+        }
+
+        self.dropped_accum += dropped;
+
+        PollStats {
+            read,
+            dropped,
+            newest,
+        }
+    }
+
     /// "Give me the newest thing right now" (not in-order).
     /// Returns true if it delivered something.
     ///
     /// This does not advance the consumer cursor.
     #[inline]
-    pub fn latest(&self, hook: impl FnOnce(u32, &T)) -> bool {
+    pub fn latest(&self, hook: impl FnOnce(Seq, &T)) -> bool {
         let newest = self.ring.newest_seq();
         if newest == 0 {
             return false;
@@ -340,14 +472,18 @@ impl<'a, T: Copy, const N: usize> Consumer<'a, T, N> {
 
 impl<'a, T: Copy, const N: usize> Drop for Consumer<'a, T, N> {
     fn drop(&mut self) {
-        self.ring.consumer_taken.store(false, Ordering::Release);
+        self.ring.consumer_count.fetch_sub(1, Ordering::Release);
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(loom)))]
 mod tests {
     use super::{SeqRing, TEST_AFTER_READ_SEQ, TEST_AFTER_READ_TARGET};
     use core::sync::atomic::Ordering;
+    use std::eprintln;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Instant;
     use std::vec::Vec;
 
     #[test]
@@ -377,6 +513,81 @@ mod tests {
         assert_eq!(&seen[..], &[(1, 10), (2, 11), (3, 12)]);
     }
 
+    #[test]
+    fn copy_up_to_writes_values_in_order() {
+        let ring = SeqRing::<u32, 8>::new();
+        let producer = ring.producer();
+        let mut consumer = ring.consumer();
+
+        producer.push(10);
+        producer.push(11);
+        producer.push(12);
+
+        let mut out = [0u32; 8];
+        let stats = consumer.copy_up_to(&mut out);
+
+        assert_eq!(stats.read, 3);
+        assert_eq!(stats.dropped, 0);
+        assert_eq!(stats.newest, 3);
+        assert_eq!(&out[..3], &[10, 11, 12]);
+    }
+
+    #[test]
+    fn copy_up_to_stops_at_out_len() {
+        let ring = SeqRing::<u32, 8>::new();
+        let producer = ring.producer();
+        let mut consumer = ring.consumer();
+
+        producer.push(10);
+        producer.push(11);
+        producer.push(12);
+
+        let mut out = [0u32; 2];
+        let stats = consumer.copy_up_to(&mut out);
+
+        assert_eq!(stats.read, 2);
+        assert_eq!(&out[..], &[10, 11]);
+
+        let mut rest = [0u32; 8];
+        let stats = consumer.copy_up_to(&mut rest);
+        assert_eq!(stats.read, 1);
+        assert_eq!(rest[0], 12);
+    }
+
+    #[test]
+    fn copy_up_to_counts_dropped_when_consumer_lags() {
+        let ring = SeqRing::<u32, 4>::new();
+        let producer = ring.producer();
+        let mut consumer = ring.consumer();
+
+        for i in 0..10 {
+            producer.push(i);
+        }
+
+        let mut out = [0u32; 10];
+        let stats = consumer.copy_up_to(&mut out);
+
+        assert_eq!(stats.read, 4);
+        assert_eq!(stats.dropped, 6);
+        assert_eq!(stats.newest, 10);
+        assert_eq!(&out[..4], &[6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn copy_up_to_empty_out_returns_newest_only() {
+        let ring = SeqRing::<u32, 4>::new();
+        let producer = ring.producer();
+        let mut consumer = ring.consumer();
+
+        producer.push(42);
+
+        let stats = consumer.copy_up_to(&mut []);
+
+        assert_eq!(stats.read, 0);
+        assert_eq!(stats.dropped, 0);
+        assert_eq!(stats.newest, 1);
+    }
+
     #[test]
     fn drops_when_consumer_lags() {
         let ring = SeqRing::<u32, 4>::new();
@@ -446,6 +657,60 @@ mod tests {
         assert_eq!(stats.newest, 1);
     }
 
+    #[test]
+    fn multiple_consumers_each_see_the_full_stream() {
+        let ring = SeqRing::<u32, 8>::new();
+        let producer = ring.producer();
+        let mut fast = ring.consumer();
+        let mut slow = ring.consumer();
+
+        producer.push(10);
+        producer.push(11);
+        producer.push(12);
+
+        let mut fast_seen = Vec::new();
+        let fast_stats = fast.poll_up_to(10, |seq, v| fast_seen.push((seq, *v)));
+
+        producer.push(13);
+
+        let mut slow_seen = Vec::new();
+        let slow_stats = slow.poll_up_to(10, |seq, v| slow_seen.push((seq, *v)));
+
+        assert_eq!(fast_stats.read, 3);
+        assert_eq!(&fast_seen[..], &[(1, 10), (2, 11), (3, 12)]);
+
+        assert_eq!(slow_stats.read, 4);
+        assert_eq!(&slow_seen[..], &[(1, 10), (2, 11), (3, 12), (4, 13)]);
+    }
+
+    #[test]
+    fn a_lagging_consumer_only_drops_its_own_items() {
+        let ring = SeqRing::<u32, 4>::new();
+        let producer = ring.producer();
+        let mut attentive = ring.consumer();
+        let mut lagging = ring.consumer();
+
+        for i in 0..10 {
+            producer.push(i);
+            let _ = attentive.poll_up_to(1, |_, _| {});
+        }
+
+        assert_eq!(attentive.dropped(), 0);
+
+        let stats = lagging.poll_up_to(10, |_, _| {});
+        assert_eq!(stats.dropped, 6);
+        assert_eq!(lagging.dropped(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "MAX_CONSUMERS")]
+    fn consumer_panics_once_max_consumers_are_active() {
+        let ring = SeqRing::<u32, 4>::new();
+        let _consumers: Vec<_> = (0..super::MAX_CONSUMERS).map(|_| ring.consumer()).collect();
+
+        let _one_too_many = ring.consumer();
+    }
+
     #[test]
     fn dropped_counter_can_reset() {
         let ring = SeqRing::<u32, 2>::new();
@@ -521,11 +786,119 @@ mod tests {
     fn push_wraps_seq_from_zero_to_one() {
         let ring = SeqRing::<u32, 4>::new();
 
-        ring.next_seq.store(u32::MAX, Ordering::Relaxed);
+        ring.next_seq.store(super::Seq::MAX, Ordering::Relaxed);
 
         let seq = ring.producer().push(1);
 
         assert_eq!(seq, 1);
         assert_eq!(ring.next_seq.load(Ordering::Relaxed), 1);
     }
+
+    /// Not a correctness test: demonstrates the producer/consumer pair driving real
+    /// contention across OS threads and reports achieved throughput. Padding the hot
+    /// fields (see [`CachePadded`](crate::cache_padded::CachePadded)) should keep the
+    /// producer's push rate roughly flat regardless of how hard the consumer is
+    /// hammering the same cache lines.
+    #[test]
+    fn producer_push_rate_under_consumer_contention() {
+        const ITEMS: u32 = 2_000_000;
+
+        let ring = Arc::new(SeqRing::<u64, 1024>::new());
+
+        let consumer_ring = ring.clone();
+        let consumer = thread::spawn(move || {
+            let mut consumer = consumer_ring.consumer();
+            let mut read = 0usize;
+            while read < ITEMS as usize {
+                let stats = consumer.poll_up_to(1024, |_, _| {});
+                read += stats.read + stats.dropped;
+            }
+        });
+
+        let producer = ring.producer();
+        let start = Instant::now();
+        for v in 0..ITEMS as u64 {
+            producer.push(v);
+        }
+        let elapsed = start.elapsed();
+
+        consumer.join().unwrap();
+
+        eprintln!(
+            "pushed {ITEMS} items in {elapsed:?} ({:.1} Mitems/s)",
+            ITEMS as f64 / elapsed.as_secs_f64() / 1e6
+        );
+    }
+}
+
+/// Model-checked tests exploring producer/consumer interleavings with `loom` instead of
+/// hoping a single-threaded run exercises the racy paths. Run with
+/// `RUSTFLAGS="--cfg loom" cargo test --release --lib -- --test-threads=1`.
+///
+/// `COUNT <= N` everywhere below: the ring never wraps around on a producer that's still
+/// running, so a slot is never overwritten while a consumer might be reading it. That's a
+/// deliberate limit, not an oversight. `read_slot`/`write_slot` go through
+/// `loom::cell::UnsafeCell`, and loom considers *any* overlap of a `with`/`with_mut` pair a
+/// causality violation regardless of what happens inside the closures — it has no notion of
+/// "read a stale value, then discard it after rechecking `slot_seq`". The seqlock's
+/// recheck-after-read only proves the returned *value* is correct; it can't make the raw
+/// access to `T` itself race-free under loom's model. So a config that genuinely reuses a
+/// slot under a concurrent reader (`N < COUNT`) doesn't explore the torn-read path — it
+/// deterministically panics on every run, because the overlap it's modeling is exactly the
+/// kind loom is built to reject. These tests stick to `COUNT <= N` to verify what loom *can*
+/// prove: the `slot_seq`/`published_seq` Acquire/Release handshake is sound and every
+/// delivered value matches its sequence number.
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use super::SeqRing;
+    use loom::sync::Arc;
+    use loom::thread;
+    use std::vec::Vec;
+
+    #[test]
+    fn spsc_delivered_values_match_their_seq() {
+        const COUNT: u32 = 2;
+
+        loom::model(|| {
+            let ring = Arc::new(SeqRing::<u32, 4>::new());
+
+            let producer_ring = ring.clone();
+            let producer = thread::spawn(move || {
+                let producer = producer_ring.producer();
+                for v in 1..=COUNT {
+                    producer.push(v);
+                }
+            });
+
+            // A fixed number of polls rather than "loop until everything is drained":
+            // looping on shared state the producer thread controls is an unbounded spin
+            // from loom's point of view and blows up the model's branch budget. One poll
+            // per produced item is enough to let the scheduler explore every interleaving
+            // while still bounding the consumer thread's steps.
+            let consumer_ring = ring.clone();
+            let consumer = thread::spawn(move || {
+                let mut consumer = consumer_ring.consumer();
+                let mut seen = Vec::new();
+                let mut read = 0usize;
+                let mut dropped = 0usize;
+                for _ in 0..COUNT {
+                    let stats = consumer.poll_up_to(COUNT as usize, |seq, v| seen.push((seq, *v)));
+                    read += stats.read;
+                    dropped += stats.dropped;
+                }
+                (seen, read, dropped)
+            });
+
+            producer.join().unwrap();
+            let (seen, read, dropped) = consumer.join().unwrap();
+
+            // Every delivered item is exactly what the producer wrote for that sequence.
+            for (seq, value) in seen {
+                assert_eq!(seq, value);
+            }
+            // The consumer never invents items: it can only have read or dropped as many
+            // as the producer could possibly have written by the time it stopped polling.
+            assert!(read + dropped <= COUNT as usize);
+        });
+    }
 }
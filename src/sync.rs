@@ -0,0 +1,57 @@
+//! Atomics used by [`SeqRing`](crate::SeqRing), abstracted so the SPSC protocol can be
+//! exhaustively checked under [loom](https://docs.rs/loom) instead of only trusting
+//! whatever thread schedule a single test run happens to hit.
+//!
+//! Normal builds re-export `AtomicBool`/`AtomicU32`/`AtomicUsize` from `core` (or
+//! `portable_atomic` on targets without native 32-bit atomics). Under `#[cfg(loom)]`
+//! the same names come from `loom::sync::atomic`, whose model checker drives many
+//! legal interleavings of a test closure. Loom's atomics are not `const`-constructible,
+//! so anything that builds a `SeqRing` must go through a regular (non-`const`) function
+//! either way, which is already how [`SeqRing::new`](crate::SeqRing::new) is written.
+//!
+//! `AtomicU32` is only pulled in when `Seq` is `u32` (the default), and `AtomicU64` only
+//! when the `64bit-seq` feature switches `Seq` to `u64` — so neither configuration
+//! carries an unused atomic width.
+//!
+//! The ring's `T` slots live behind [`UnsafeCell`] for the same reason: under
+//! `#[cfg(loom)]` this is `loom::cell::UnsafeCell`, so the model checker also tracks the
+//! data reads/writes the seqlock protocol is meant to guard, not just the atomics around
+//! them. `loom::cell::UnsafeCell` has no raw `.get()` pointer, only closure-based
+//! `with`/`with_mut` accessors, so [`SeqRing`](crate::SeqRing) reaches its slots through
+//! small `read_slot`/`write_slot` helpers instead of a bare pointer dereference.
+
+#[cfg(loom)]
+pub(crate) use loom::cell::UnsafeCell;
+#[cfg(not(loom))]
+pub(crate) use core::cell::UnsafeCell;
+
+#[cfg(all(loom, not(feature = "64bit-seq")))]
+pub(crate) use loom::sync::atomic::AtomicU32;
+#[cfg(loom)]
+pub(crate) use loom::sync::atomic::{AtomicBool, AtomicUsize};
+#[cfg(all(loom, feature = "64bit-seq"))]
+pub(crate) use loom::sync::atomic::AtomicU64;
+
+#[cfg(all(not(loom), target_has_atomic = "32"))]
+pub(crate) use core::sync::atomic::{AtomicBool, AtomicUsize};
+#[cfg(all(not(loom), not(feature = "64bit-seq"), target_has_atomic = "32"))]
+pub(crate) use core::sync::atomic::AtomicU32;
+#[cfg(all(not(loom), feature = "64bit-seq", target_has_atomic = "64"))]
+pub(crate) use core::sync::atomic::AtomicU64;
+
+#[cfg(all(not(loom), not(target_has_atomic = "32"), feature = "portable-atomic"))]
+pub(crate) use portable_atomic::{AtomicBool, AtomicUsize};
+#[cfg(all(
+    not(loom),
+    not(feature = "64bit-seq"),
+    not(target_has_atomic = "32"),
+    feature = "portable-atomic"
+))]
+pub(crate) use portable_atomic::AtomicU32;
+#[cfg(all(
+    not(loom),
+    feature = "64bit-seq",
+    not(target_has_atomic = "64"),
+    feature = "portable-atomic"
+))]
+pub(crate) use portable_atomic::AtomicU64;